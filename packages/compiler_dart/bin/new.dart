@@ -0,0 +1,83 @@
+import 'dart:io';
+
+import 'package:args/args.dart';
+import 'package:args/command_runner.dart';
+import 'package:compiler/compiler.dart';
+import 'package:path/path.dart' as p;
+
+// Creates a new Candy package containing a `candyspec.yml` and a `main.candy`
+// with a `main` function, ready to be compiled with `candy2dart`.
+//
+// Usage: dart ./bin/new.dart <package-directory>
+
+const _usage = 'dart ./bin/new.dart path/to/my_package';
+
+/// The package name becomes the name of the generated Dart package, and
+/// `candy2dart` and the language server derive it from the package directory's
+/// name. Hence, that name has to be a valid Dart package name.
+final _packageNamePattern = RegExp(r'^[a-z_][a-z0-9_]*$');
+
+Future<void> main(List<String> arguments) async {
+  final parser = ArgParser();
+
+  try {
+    final result = parser.parse(arguments);
+    final rest = result.rest;
+    if (rest.length != 1) {
+      throw UsageException(
+        'Please enter the directory of the package to create.',
+        _usage,
+      );
+    }
+
+    final packageDirectory = Directory(rest.single);
+    final name = p.basename(p.normalize(packageDirectory.absolute.path));
+    if (!_packageNamePattern.hasMatch(name)) {
+      throw UsageException(
+        '`$name` is not a valid package name: The name of the package '
+            'directory is used as a Dart package name, so it may only contain '
+            'lowercase letters, digits, and underscores and must not start '
+            'with a digit. Please rename the directory.',
+        _usage,
+      );
+    }
+
+    final type = FileSystemEntity.typeSync(packageDirectory.path);
+    if (type != FileSystemEntityType.notFound &&
+        type != FileSystemEntityType.directory) {
+      throw UsageException(
+        '`${packageDirectory.absolute.path}` already exists and is not a '
+            'directory.',
+        _usage,
+      );
+    }
+    if (packageDirectory.existsSync() &&
+        packageDirectory.listSync().isNotEmpty) {
+      throw UsageException(
+        '`${packageDirectory.absolute.path}` already exists and is not empty.',
+        _usage,
+      );
+    }
+
+    await File(p.join(packageDirectory.path, candyspecName))
+        .create(recursive: true)
+        .then((it) => it.writeAsString('name: $name\n'));
+    await File(p.join(
+      packageDirectory.path,
+      srcDirectoryName,
+      'main$candyFileExtension',
+    )).create(recursive: true).then((it) => it.writeAsString(
+          'fun main() {\n'
+          '  print("Hello, world!")\n'
+          '}\n',
+        ));
+
+    print('✅ Created package `$name` in `${packageDirectory.path}`.');
+  } on FormatException catch (e) {
+    print(UsageException(e.message, _usage));
+    exit(HttpStatus.badRequest);
+  } on UsageException catch (e) {
+    print(e);
+    exit(HttpStatus.badRequest);
+  }
+}
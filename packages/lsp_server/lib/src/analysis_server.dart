@@ -5,7 +5,9 @@ import 'package:analyzer/exception/exception.dart';
 import 'package:collection/collection.dart';
 import 'package:compiler/compiler.dart';
 import 'package:dartx/dartx.dart' hide Range;
+import 'package:meta/meta.dart';
 import 'package:path/path.dart' as p;
+import 'package:pedantic/pedantic.dart';
 
 import 'channel.dart';
 import 'error_codes.dart';
@@ -295,22 +297,88 @@ class AnalysisServer {
   QueryConfig get queryConfig => _queryConfig;
 
   Map<ResourceId, List<ReportedCompilerError>> _errors = {};
+
+  /// Analyzes the changed file right away and then the rest of the package, as
+  /// other files might depend on it.
   void onFileChanged(ResourceId resourceId) {
     final context = queryConfig.createContext()
       ..callQuery(calculateFullHir, resourceId);
-    _updateErrors(context.reportedErrorsByResourceId);
+    _updateErrors(
+      context.reportedErrorsByResourceId,
+      analyzedResourceIds: [resourceId],
+    );
     updateTypeLabels(this, resourceId);
+    unawaited(analyzePackage());
   }
 
-  void _updateErrors(Map<ResourceId, List<ReportedCompilerError>> errors) {
+  /// Incremented for every package analysis so that older ones can notice
+  /// they're outdated.
+  int _packageAnalysisGeneration = 0;
+
+  /// Analyzes all source files of the package, including ones that aren't
+  /// opened in the editor or only exist as overlays, and publishes their
+  /// diagnostics.
+  ///
+  /// This still runs on the server's isolate, but yields to the event loop
+  /// before each file so that other messages can be handled in between. If
+  /// another analysis is started in the meantime, this one is abandoned.
+  Future<void> analyzePackage() async {
+    final generation = ++_packageAnalysisGeneration;
+    try {
+      final context = queryConfig.createContext();
+      // All files are analyzed in the same context so that queries they share
+      // (e.g., of common dependencies) are only evaluated once.
+      final resourceIds = resourceProvider
+          .getAllFileResourceIds(QueryContext(context), queryConfig.packageId)
+          .where((it) => it.isCandySourceFile)
+          .toList();
+      for (final resourceId in resourceIds) {
+        await Future<void>(() {});
+        if (generation != _packageAnalysisGeneration) return;
+
+        context.callQuery(calculateFullHir, resourceId);
+      }
+
+      _updateErrors(
+        context.reportedErrorsByResourceId,
+        analyzedResourceIds: [
+          ...resourceIds,
+          // Clears errors of files that no longer exist.
+          ..._errors.keys.where((it) => it?.packageId == queryConfig.packageId),
+        ],
+      );
+    } catch (error, stackTrace) {
+      logException(
+        'An error occurred while analyzing the package',
+        error,
+        stackTrace,
+      );
+    }
+  }
+
+  /// Publishes diagnostics for the given [errors].
+  ///
+  /// The errors of all [analyzedResourceIds] are replaced, as are those of
+  /// every resource in [errors]. Errors of other resources are kept.
+  void _updateErrors(
+    Map<ResourceId, List<ReportedCompilerError>> errors, {
+    @required List<ResourceId> analyzedResourceIds,
+  }) {
     sendLogMessage('New errors: $errors');
+    final mergedErrors = {
+      ..._errors,
+      for (final resourceId in analyzedResourceIds)
+        resourceId: <ReportedCompilerError>[],
+      ...errors,
+    };
+
     final equality = DeepCollectionEquality.unordered();
-    for (final resourceId in (_errors.keys + errors.keys).toSet()) {
+    for (final resourceId in (_errors.keys + mergedErrors.keys).toSet()) {
       // TODO(JonasWanke): handle errors without a location
       if (resourceId == null) continue;
 
-      final oldErrors = _errors[resourceId];
-      final newErrors = errors[resourceId] ?? [];
+      final oldErrors = _errors[resourceId] ?? [];
+      final newErrors = mergedErrors[resourceId] ?? [];
       if (equality.equals(oldErrors, newErrors)) continue;
 
       final diagnostics = newErrors
@@ -336,7 +404,7 @@ class AnalysisServer {
         jsonRpcVersion,
       ));
     }
-    _errors = errors;
+    _errors = mergedErrors;
   }
 
   ResourceId fileUriToResourceId(String uri) {
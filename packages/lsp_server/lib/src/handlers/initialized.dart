@@ -1,3 +1,5 @@
+import 'package:pedantic/pedantic.dart';
+
 import '../analysis_server.dart';
 import '../generated/lsp_protocol/protocol_generated.dart';
 import '../generated/lsp_protocol/protocol_special.dart';
@@ -18,6 +20,9 @@ class IntializedMessageHandler extends MessageHandler<InitializedParams, void> {
   ErrorOr<void> handle(InitializedParams params, CancellationToken token) {
     server.messageHandler = InitializedStateMessageHandler(server);
     _performDynamicRegistration();
+    // The analysis yields between files, so this handler (and following
+    // messages) don't have to wait for the whole package to be analyzed.
+    unawaited(server.analyzePackage());
     return success();
   }
 
@@ -1,3 +1,5 @@
+import 'package:pedantic/pedantic.dart';
+
 import '../analysis_server.dart';
 import '../error_codes.dart';
 import '../generated/lsp_protocol/protocol_generated.dart';
@@ -76,6 +78,8 @@ class TextDocumentCloseHandler
   ) {
     final resourceId = server.fileUriToResourceId(params.textDocument.uri);
     server.resourceProvider.removeOverlay(resourceId);
+    // Without the overlay, diagnostics have to reflect the files on disk again.
+    unawaited(server.analyzePackage());
     return success();
   }
 }
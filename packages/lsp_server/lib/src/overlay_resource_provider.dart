@@ -24,8 +24,10 @@ class OverlayResourceProvider extends ResourceProvider {
     QueryContext context,
     PackageId packageId,
   ) {
-    // TODO(JonasWanke): check overlays
-    return baseProvider.getAllFileResourceIds(context, packageId);
+    return {
+      ...baseProvider.getAllFileResourceIds(context, packageId),
+      ..._overlays.keys.where((it) => it.packageId == packageId),
+    }.toList();
   }
 
   @override
@@ -225,7 +225,8 @@ class LexerGrammar {
 
   // SECTION: literals
 
-  static final IntegerLiteral = HexLiteral | BinLiteral | DecLiteral;
+  static final IntegerLiteral =
+      HexLiteral | OctLiteral | BinLiteral | DecLiteral;
 
   // TODO(JonasWanke): disallow trailing underscore
   // formerly: DecDigit & DecDigitOrSeparator.star() & DecDigit | DecDigit
@@ -254,6 +255,21 @@ class LexerGrammar {
       char('A') | char('B') | char('C') | char('D') | char('E') | char('F');
   static final _HexDigitOrSeparator = _HexDigit | char('_');
 
+  // TODO(JonasWanke): disallow leading/trailing underscore
+  static final OctLiteral = (_octLiteralPrefix & _OctDigitOrSeparator.plus())
+      .tokenizeInteger(radix: 8, hasPrefix: true);
+  static final _octLiteralPrefix = char('0') & (char('o') | char('O'));
+
+  static final _OctDigit = char('0') |
+      char('1') |
+      char('2') |
+      char('3') |
+      char('4') |
+      char('5') |
+      char('6') |
+      char('7');
+  static final _OctDigitOrSeparator = _OctDigit | char('_');
+
   // TODO(JonasWanke): disallow leading/trailing underscore
   // formerly: _binLiteralPrefix & _BinDigit & (_BinDigitOrSeparator.star() & _BinDigit).optional()
   static final BinLiteral = (_binLiteralPrefix & _BinDigitOrSeparator.plus())
@@ -30,6 +30,12 @@ void main() {
           nodeMapper: (value, fullSpan) =>
               Literal(0, IntLiteralToken(value, span: fullSpan)),
         );
+        tableTestExpressionParser<int, Literal<int>>(
+          'octal',
+          table: validOctIntLiterals,
+          nodeMapper: (value, fullSpan) =>
+              Literal(0, IntLiteralToken(value, span: fullSpan)),
+        );
         tableTestExpressionParser<int, Literal<int>>(
           'binary',
           table: validBinIntLiterals,
@@ -415,6 +421,22 @@ final validHexIntLiterals = {
   '0x1000': 0x1000,
   '0x1_000': 0x1000,
 };
+final someValidOctIntLiterals = {
+  '0o0': 0,
+  '0o10': 8,
+};
+final validOctIntLiterals = {
+  ...someValidOctIntLiterals,
+  '0o1': 1,
+  '0o7': 7,
+  '0O7': 7,
+  '0o1_0': 8,
+  '0o100': 64,
+  '0o10_0': 64,
+  '0o1_0_0': 64,
+  '0o777': 511,
+  '0o7_77': 511,
+};
 final someValidBinIntLiterals = {
   '0b0': 0x0,
   '0b10': 0x2,
@@ -436,6 +458,7 @@ final someValidIntLiterals = {
 final validIntLiterals = {
   ...validDecIntLiterals,
   ...validHexIntLiterals,
+  ...validOctIntLiterals,
   ...validBinIntLiterals,
 };
 final someValidBoolLiterals = {